@@ -37,6 +37,27 @@ pub enum QExpr {
     ///
     /// Evaluation requires field-aware indexing (or a compiler that rewrites into field-specific terms).
     Field(FieldName, Box<QExpr>),
+    /// Relevance boost: scale the contribution of `inner` without changing which documents match.
+    ///
+    /// This is the semantic payload behind operators like Xapian's `OP_SCALE_WEIGHT`
+    /// (e.g. `title:foo^2.0`). It carries a scoring hint, not a matching constraint.
+    Boost(Boost),
+    /// A value range constraint over a field (e.g. `price:10..50`, `date:2020..2021`).
+    ///
+    /// This is the semantic payload behind value range queries like Xapian's `OP_VALUE_RANGE`.
+    Range(Range),
+    /// A wildcard/prefix pattern that a backend expands into term alternatives
+    /// (e.g. `quer*`, `?ase`).
+    ///
+    /// This is the semantic payload behind operators like Xapian's `OP_WILDCARD`.
+    Wildcard(Wildcard),
+    /// Synonym set: matches the union of its children, but presents a single combined
+    /// statistic to the scorer instead of weighting each child independently.
+    ///
+    /// This is the semantic payload behind Xapian's `OP_SYNONYM`, and is distinct from
+    /// `Or`: `Or` is "union for matching *and* scoring", `Synonym` is "union for matching,
+    /// one term's worth of scoring".
+    Synonym(Vec<QExpr>),
 }
 
 /// A normalized term token.
@@ -56,26 +77,51 @@ impl Term {
     }
 }
 
-/// A phrase of ordered terms.
+/// A phrase of ordered slots.
+///
+/// Slots are [`PhraseSlot::Gap`] rather than simply omitted so a positional backend can
+/// tell the difference between "adjacent terms" and "terms with a removed stop word between
+/// them", and require the following term to appear the right number of positions later.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Phrase {
-    /// Ordered terms.
-    pub terms: Vec<Term>,
+    /// Ordered slots.
+    pub terms: Vec<PhraseSlot>,
 }
 
 impl Phrase {
-    /// Create a phrase.
-    pub fn new(terms: Vec<Term>) -> Self {
+    /// Create a phrase from slots, e.g. a mix of terms and elided stop-word positions.
+    pub fn new(terms: Vec<PhraseSlot>) -> Self {
         Self { terms }
     }
 
-    /// Returns true if the phrase has no terms (or all terms are blank).
+    /// Create a phrase with no gaps, the common case of a phrase indexed without stop words.
+    pub fn from_terms(terms: Vec<Term>) -> Self {
+        Self {
+            terms: terms.into_iter().map(PhraseSlot::Term).collect(),
+        }
+    }
+
+    /// Returns true if the phrase has no slots, or every slot is a gap or a blank term.
     pub fn is_blank(&self) -> bool {
-        self.terms.is_empty() || self.terms.iter().all(|t| t.is_blank())
+        self.terms.is_empty()
+            || self.terms.iter().all(|s| match s {
+                PhraseSlot::Term(t) => t.is_blank(),
+                PhraseSlot::Gap => true,
+            })
     }
 }
 
+/// A single slot in a [`Phrase`]: a concrete term, or a gap left by an elided stop word.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PhraseSlot {
+    /// A term occupying this position.
+    Term(Term),
+    /// A position where a term was elided (e.g. a stop word), still constraining adjacency.
+    Gap,
+}
+
 /// A proximity query over ordered terms.
 ///
 /// This represents constraints like “the terms occur within `window` tokens”.
@@ -113,6 +159,183 @@ impl Near {
     }
 }
 
+/// A relevance boost applied to a subquery.
+///
+/// Matches exactly what `inner` matches; only scoring is affected.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Boost {
+    /// The boosted subquery.
+    pub inner: Box<QExpr>,
+    /// Multiplicative weight. Must be finite and non-negative.
+    pub weight: f32,
+}
+
+impl Boost {
+    /// Create a boost over `inner` with the given `weight`.
+    pub fn new(inner: QExpr, weight: f32) -> Self {
+        Self {
+            inner: Box::new(inner),
+            weight,
+        }
+    }
+
+    /// Returns true if the weight is non-finite or negative.
+    pub fn is_invalid_weight(&self) -> bool {
+        !self.weight.is_finite() || self.weight < 0.0
+    }
+}
+
+// `f32` has no total equality, so `Boost` (and anything containing it) compares/hashes
+// the weight by bit pattern. This keeps `QExpr: Eq + Hash` intact for normalization/dedup.
+impl PartialEq for Boost {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.weight.to_bits() == other.weight.to_bits()
+    }
+}
+
+impl Eq for Boost {}
+
+impl std::hash::Hash for Boost {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+        self.weight.to_bits().hash(state);
+    }
+}
+
+/// A value range constraint over a field.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Range {
+    /// The field the range applies to.
+    pub field: FieldName,
+    /// Lower bound, if any.
+    pub lower: Option<RangeBound>,
+    /// Whether `lower` is itself included in the range (`>=` vs `>`).
+    pub lower_inclusive: bool,
+    /// Upper bound, if any.
+    pub upper: Option<RangeBound>,
+    /// Whether `upper` is itself included in the range (`<=` vs `<`).
+    pub upper_inclusive: bool,
+}
+
+impl Range {
+    /// Create a range constraint. At least one of `lower`/`upper` should be set; see [`validate`].
+    pub fn new(
+        field: FieldName,
+        lower: Option<RangeBound>,
+        lower_inclusive: bool,
+        upper: Option<RangeBound>,
+        upper_inclusive: bool,
+    ) -> Self {
+        Self {
+            field,
+            lower,
+            lower_inclusive,
+            upper,
+            upper_inclusive,
+        }
+    }
+
+    /// Returns true if the numeric bounds are present and inverted (`lower > upper`).
+    pub fn is_inverted(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(RangeBound::Integer(lo)), Some(RangeBound::Integer(hi))) => lo > hi,
+            (Some(RangeBound::Float(lo)), Some(RangeBound::Float(hi))) => lo > hi,
+            _ => false,
+        }
+    }
+}
+
+/// A typed bound for a [`Range`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub enum RangeBound {
+    /// An integer bound (e.g. a count).
+    Integer(i64),
+    /// A floating-point bound (e.g. a price).
+    Float(f64),
+    /// A lexical bound (e.g. a date string or other sortable text).
+    Text(String),
+}
+
+// `f64` has no total equality, so `RangeBound::Float` compares/hashes by bit pattern.
+// This keeps `QExpr: Eq + Hash` intact for normalization/dedup.
+impl PartialEq for RangeBound {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RangeBound::Integer(a), RangeBound::Integer(b)) => a == b,
+            (RangeBound::Float(a), RangeBound::Float(b)) => a.to_bits() == b.to_bits(),
+            (RangeBound::Text(a), RangeBound::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RangeBound {}
+
+impl std::hash::Hash for RangeBound {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            RangeBound::Integer(i) => i.hash(state),
+            RangeBound::Float(f) => f.to_bits().hash(state),
+            RangeBound::Text(s) => s.hash(state),
+        }
+    }
+}
+
+/// A wildcard/prefix pattern over terms.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wildcard {
+    /// Field the pattern is scoped to, if any.
+    pub field: Option<FieldName>,
+    /// The pattern, e.g. `quer*` or `?ase`.
+    pub pattern: String,
+    /// Bound on how many term alternatives the pattern may expand into.
+    ///
+    /// Absence means the backend's default expansion limit applies; it does
+    /// not mean "unbounded".
+    pub max_expansion: Option<u32>,
+    /// The shape of the pattern, so simpler indexes can reject forms they can't serve.
+    pub kind: WildcardKind,
+}
+
+impl Wildcard {
+    /// Create a wildcard constraint.
+    pub fn new(
+        field: Option<FieldName>,
+        pattern: impl Into<String>,
+        max_expansion: Option<u32>,
+        kind: WildcardKind,
+    ) -> Self {
+        Self {
+            field,
+            pattern: pattern.into(),
+            max_expansion,
+            kind,
+        }
+    }
+
+    /// Returns true if the pattern is blank or consists entirely of wildcard characters
+    /// (which would expand to effectively every term in the index).
+    pub fn is_blank(&self) -> bool {
+        let trimmed = self.pattern.trim();
+        trimmed.is_empty() || trimmed.chars().all(|c| c == '*' || c == '?')
+    }
+}
+
+/// The shape of a [`Wildcard`] pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WildcardKind {
+    /// A trailing prefix pattern (e.g. `foo*`), the form most indexes can serve cheaply.
+    Prefix,
+    /// A general glob pattern (e.g. `*foo*`, `f?o`), which not every index supports.
+    Glob,
+}
+
 /// A field name.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -128,6 +351,26 @@ impl FieldName {
     pub fn is_blank(&self) -> bool {
         self.0.trim().is_empty()
     }
+
+    /// Returns a loosely-normalized form: ASCII-lowercased, with `_`, `-`, and whitespace
+    /// stripped.
+    ///
+    /// Mirrors ICU "loose matching": names that differ only by ASCII case, underscores,
+    /// hyphens, or whitespace are treated as the same field (`publishDate`, `publish_date`,
+    /// and `Publish-Date` all normalize to `publishdate`). This is a naming-convention rule
+    /// only; it does not tokenize or otherwise interpret the name.
+    pub fn normalized(&self) -> String {
+        self.0
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-') && !c.is_whitespace())
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    }
+
+    /// Returns true if `self` and `other` are equal under loose matching; see [`normalized`](Self::normalized).
+    pub fn loose_eq(&self, other: &FieldName) -> bool {
+        self.normalized() == other.normalized()
+    }
 }
 
 /// Structural validation errors for `QExpr`.
@@ -139,10 +382,26 @@ pub enum ValidateError {
     BlankPhrase,
     /// A `Near` node contained fewer than 2 usable terms or an invalid window.
     BlankNear,
-    /// An `And`/`Or` node had no children.
+    /// An `And`/`Or`/`Synonym` node had no children.
     EmptyJunction,
     /// A `Field` node had a blank field name.
     BlankFieldName,
+    /// A `Boost` node had a non-finite or negative weight.
+    InvalidBoost,
+    /// A `Range` node had neither a lower nor an upper bound.
+    EmptyRange,
+    /// A `Range` node had a numeric lower bound greater than its upper bound.
+    InvertedRange,
+    /// A `Wildcard` node had a blank pattern or a pattern of only wildcard characters.
+    BlankWildcard,
+}
+
+/// Like [`validate`], but first canonicalizes every `Field` node's name via
+/// [`FieldName::normalized`], so differing naming conventions (`publishDate` vs
+/// `publish_date`) validate as the same field. See [`normalize_loose_fields`] for the
+/// `normalize`-side counterpart.
+pub fn validate_loose_fields(expr: &QExpr) -> Result<(), ValidateError> {
+    validate(&canonicalize_field_names(expr.clone()))
 }
 
 /// Validate a query expression for basic structural invariants.
@@ -171,7 +430,7 @@ pub fn validate(expr: &QExpr) -> Result<(), ValidateError> {
                 Ok(())
             }
         }
-        QExpr::And(xs) | QExpr::Or(xs) => {
+        QExpr::And(xs) | QExpr::Or(xs) | QExpr::Synonym(xs) => {
             if xs.is_empty() {
                 return Err(ValidateError::EmptyJunction);
             }
@@ -187,5 +446,903 @@ pub fn validate(expr: &QExpr) -> Result<(), ValidateError> {
             }
             validate(inner)
         }
+        QExpr::Boost(b) => {
+            if b.is_invalid_weight() {
+                return Err(ValidateError::InvalidBoost);
+            }
+            validate(&b.inner)
+        }
+        QExpr::Range(r) => {
+            if r.field.is_blank() {
+                return Err(ValidateError::BlankFieldName);
+            }
+            if r.lower.is_none() && r.upper.is_none() {
+                return Err(ValidateError::EmptyRange);
+            }
+            if r.is_inverted() {
+                return Err(ValidateError::InvertedRange);
+            }
+            Ok(())
+        }
+        QExpr::Wildcard(w) => {
+            if w.field.as_ref().is_some_and(|f| f.is_blank()) {
+                return Err(ValidateError::BlankFieldName);
+            }
+            if w.is_blank() {
+                Err(ValidateError::BlankWildcard)
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Produce a canonical form of `expr`, useful for caching, deduplication, and cheaper
+/// backend compilation.
+///
+/// Rewrites applied, bottom-up:
+/// - nested same-kind junctions are flattened (`And([And([a,b]),c])` -> `And([a,b,c])`, and
+///   likewise for `Or`);
+/// - single-child junctions collapse to their child (`And([x])` -> `x`);
+/// - duplicate children within a junction are removed, order-insensitively but preserving
+///   first-seen order;
+/// - negations are pushed inward via De Morgan (`Not(And([a,b]))` -> `Or([Not(a),Not(b)])`),
+///   and double negation folds away (`Not(Not(x))` -> `x`);
+/// - a `Field` wrapper common to every child of a junction is hoisted above the junction.
+///
+/// `normalize` is idempotent (`normalize(normalize(e)) == normalize(e)`) and never turns a
+/// valid expression into one that fails [`validate`].
+pub fn normalize(expr: QExpr) -> QExpr {
+    match expr {
+        QExpr::Term(_)
+        | QExpr::Phrase(_)
+        | QExpr::Near(_)
+        | QExpr::Range(_)
+        | QExpr::Wildcard(_) => expr,
+        QExpr::Field(name, inner) => QExpr::Field(name, Box::new(normalize(*inner))),
+        QExpr::Boost(b) => QExpr::Boost(Boost {
+            inner: Box::new(normalize(*b.inner)),
+            weight: b.weight,
+        }),
+        QExpr::Synonym(xs) => QExpr::Synonym(xs.into_iter().map(normalize).collect()),
+        QExpr::Not(inner) => normalize_not(normalize(*inner)),
+        QExpr::And(xs) => normalize_junction(xs, true),
+        QExpr::Or(xs) => normalize_junction(xs, false),
+    }
+}
+
+/// Like [`normalize`], but first canonicalizes every `Field` node's name via
+/// [`FieldName::normalized`], so differing naming conventions (`publishDate` vs
+/// `publish_date`) collapse to the same field before the rest of canonicalization runs — this
+/// is what lets the field-hoisting rule in [`normalize`] see them as one field. See
+/// [`validate_loose_fields`] for the `validate`-side counterpart.
+pub fn normalize_loose_fields(expr: QExpr) -> QExpr {
+    normalize(canonicalize_field_names(expr))
+}
+
+/// Apply De Morgan's laws and double-negation folding to an already-normalized `inner`.
+fn normalize_not(inner: QExpr) -> QExpr {
+    match inner {
+        QExpr::Not(x) => *x,
+        QExpr::And(xs) => normalize(QExpr::Or(
+            xs.into_iter().map(|x| QExpr::Not(Box::new(x))).collect(),
+        )),
+        QExpr::Or(xs) => normalize(QExpr::And(
+            xs.into_iter().map(|x| QExpr::Not(Box::new(x))).collect(),
+        )),
+        other => QExpr::Not(Box::new(other)),
+    }
+}
+
+/// Flatten, dedup, collapse, and field-hoist an `And`/`Or` junction's children.
+fn normalize_junction(xs: Vec<QExpr>, is_and: bool) -> QExpr {
+    let mut flattened = Vec::with_capacity(xs.len());
+    for x in xs {
+        match normalize(x) {
+            QExpr::And(inner) if is_and => flattened.extend(inner),
+            QExpr::Or(inner) if !is_and => flattened.extend(inner),
+            other => flattened.push(other),
+        }
+    }
+
+    let mut seen = std::collections::HashSet::with_capacity(flattened.len());
+    let mut deduped = Vec::with_capacity(flattened.len());
+    for x in flattened {
+        if seen.insert(x.clone()) {
+            deduped.push(x);
+        }
+    }
+
+    if deduped.len() == 1 {
+        return deduped.into_iter().next().unwrap();
+    }
+
+    if let Some(field) = common_field(&deduped) {
+        let unwrapped = deduped
+            .into_iter()
+            .map(|x| match x {
+                QExpr::Field(_, inner) => *inner,
+                other => other,
+            })
+            .collect();
+        let hoisted = if is_and {
+            QExpr::And(unwrapped)
+        } else {
+            QExpr::Or(unwrapped)
+        };
+        return QExpr::Field(field, Box::new(normalize(hoisted)));
+    }
+
+    if is_and {
+        QExpr::And(deduped)
+    } else {
+        QExpr::Or(deduped)
+    }
+}
+
+/// Returns the shared field name if every expression in `xs` is a `Field` node scoping it.
+fn common_field(xs: &[QExpr]) -> Option<FieldName> {
+    let mut names = xs.iter().map(|x| match x {
+        QExpr::Field(name, _) => Some(name),
+        _ => None,
+    });
+    let first = names.next()??.clone();
+    if names.all(|n| n == Some(&first)) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// A visitor over `QExpr` trees, for read-only analysis that borrows from the tree (e.g.
+/// collecting terms, checking invariants) or stateful rewriting via companions like [`fold`].
+///
+/// Parameterized over the tree's lifetime `'a` (mirroring `syn::Visit<'ast>`) so hooks can
+/// hand out borrows, such as `&'a Term`, that outlive the individual `visit_*` call and can be
+/// collected by the caller. Each hook has a default implementation that recurses into children
+/// via [`walk`]; override only the hooks relevant to your analysis.
+pub trait Visitor<'a> {
+    /// Visit a `Term` node. Default: no-op (terms have no children).
+    fn visit_term(&mut self, _term: &'a Term) {}
+    /// Visit a `Phrase` node. Default: call `visit_term` for each term slot (gaps are skipped).
+    fn visit_phrase(&mut self, phrase: &'a Phrase) {
+        for slot in &phrase.terms {
+            if let PhraseSlot::Term(t) = slot {
+                self.visit_term(t);
+            }
+        }
+    }
+    /// Visit a `Near` node. Default: call `visit_term` for each of its terms.
+    fn visit_near(&mut self, near: &'a Near) {
+        for t in &near.terms {
+            self.visit_term(t);
+        }
+    }
+    /// Visit an `And` node. Default: recurse into each child.
+    fn visit_and(&mut self, xs: &'a [QExpr]) {
+        for x in xs {
+            walk(x, self);
+        }
+    }
+    /// Visit an `Or` node. Default: recurse into each child.
+    fn visit_or(&mut self, xs: &'a [QExpr]) {
+        for x in xs {
+            walk(x, self);
+        }
+    }
+    /// Visit a `Not` node. Default: recurse into the inner expression.
+    fn visit_not(&mut self, inner: &'a QExpr) {
+        walk(inner, self);
+    }
+    /// Visit a `Field` node. Default: recurse into the inner expression.
+    fn visit_field(&mut self, _name: &'a FieldName, inner: &'a QExpr) {
+        walk(inner, self);
+    }
+    /// Visit a `Boost` node. Default: recurse into the inner expression.
+    fn visit_boost(&mut self, boost: &'a Boost) {
+        walk(&boost.inner, self);
+    }
+    /// Visit a `Range` node. Default: no-op (ranges have no `QExpr` children).
+    fn visit_range(&mut self, _range: &'a Range) {}
+    /// Visit a `Wildcard` node. Default: no-op (wildcards have no `QExpr` children).
+    fn visit_wildcard(&mut self, _wildcard: &'a Wildcard) {}
+    /// Visit a `Synonym` node. Default: recurse into each child.
+    fn visit_synonym(&mut self, xs: &'a [QExpr]) {
+        for x in xs {
+            walk(x, self);
+        }
+    }
+}
+
+/// Dispatch `expr` to the matching `visit_*` hook on `visitor`.
+///
+/// Hooks recurse into children by default, so calling `walk` on the root of a tree visits
+/// every node unless a hook overrides the default to stop early.
+pub fn walk<'a, V: Visitor<'a> + ?Sized>(expr: &'a QExpr, visitor: &mut V) {
+    match expr {
+        QExpr::Term(t) => visitor.visit_term(t),
+        QExpr::Phrase(p) => visitor.visit_phrase(p),
+        QExpr::Near(n) => visitor.visit_near(n),
+        QExpr::And(xs) => visitor.visit_and(xs),
+        QExpr::Or(xs) => visitor.visit_or(xs),
+        QExpr::Not(x) => visitor.visit_not(x),
+        QExpr::Field(name, inner) => visitor.visit_field(name, inner),
+        QExpr::Boost(b) => visitor.visit_boost(b),
+        QExpr::Range(r) => visitor.visit_range(r),
+        QExpr::Wildcard(w) => visitor.visit_wildcard(w),
+        QExpr::Synonym(xs) => visitor.visit_synonym(xs),
+    }
+}
+
+/// Rebuild `expr` bottom-up, applying `f` to each node after its children have already been
+/// folded.
+///
+/// This is the building block for rewrites like renaming fields or lowering `Near` into a
+/// backend-specific form; see [`map_fields`] for an example built on top of it.
+pub fn fold<F: FnMut(QExpr) -> QExpr>(expr: QExpr, f: &mut F) -> QExpr {
+    let rebuilt = match expr {
+        QExpr::Term(_)
+        | QExpr::Phrase(_)
+        | QExpr::Near(_)
+        | QExpr::Range(_)
+        | QExpr::Wildcard(_) => expr,
+        QExpr::And(xs) => QExpr::And(xs.into_iter().map(|x| fold(x, f)).collect()),
+        QExpr::Or(xs) => QExpr::Or(xs.into_iter().map(|x| fold(x, f)).collect()),
+        QExpr::Synonym(xs) => QExpr::Synonym(xs.into_iter().map(|x| fold(x, f)).collect()),
+        QExpr::Not(inner) => QExpr::Not(Box::new(fold(*inner, f))),
+        QExpr::Field(name, inner) => QExpr::Field(name, Box::new(fold(*inner, f))),
+        QExpr::Boost(b) => QExpr::Boost(Boost {
+            inner: Box::new(fold(*b.inner, f)),
+            weight: b.weight,
+        }),
+    };
+    f(rebuilt)
+}
+
+/// Collect references to every `Term` appearing anywhere in `expr`, in traversal order.
+pub fn collect_terms(expr: &QExpr) -> Vec<&Term> {
+    struct TermCollector<'a> {
+        terms: Vec<&'a Term>,
+    }
+
+    impl<'a> Visitor<'a> for TermCollector<'a> {
+        fn visit_term(&mut self, term: &'a Term) {
+            self.terms.push(term);
+        }
+    }
+
+    let mut collector = TermCollector { terms: Vec::new() };
+    walk(expr, &mut collector);
+    collector.terms
+}
+
+/// Rewrite every `FieldName` in `expr` via `f`, leaving matching semantics otherwise unchanged.
+///
+/// Covers every node that carries a `FieldName` directly: `Field`, and the field-scoping
+/// `Range`/`Wildcard` nodes (whose optional `field` is left as `None` if absent).
+pub fn map_fields(expr: QExpr, f: impl Fn(FieldName) -> FieldName) -> QExpr {
+    fold(expr, &mut |node| match node {
+        QExpr::Field(name, inner) => QExpr::Field(f(name), inner),
+        QExpr::Range(r) => QExpr::Range(Range { field: f(r.field), ..r }),
+        QExpr::Wildcard(w) => QExpr::Wildcard(Wildcard {
+            field: w.field.map(&f),
+            ..w
+        }),
+        other => other,
+    })
+}
+
+/// Rewrite every field name in `expr` (on `Field` nodes and on the fields of `Range`/`Wildcard`
+/// nodes) to its [`FieldName::normalized`] loose-matching form.
+///
+/// This is the building block behind [`validate_loose_fields`] and [`normalize_loose_fields`];
+/// call it directly if you want canonicalized field names without also validating or
+/// normalizing the rest of the expression.
+pub fn canonicalize_field_names(expr: QExpr) -> QExpr {
+    map_fields(expr, |name| FieldName::new(name.normalized()))
+}
+
+#[cfg(test)]
+mod boost_tests {
+    use super::*;
+
+    fn term(s: &str) -> QExpr {
+        QExpr::Term(Term::new(s))
+    }
+
+    #[test]
+    fn zero_and_negative_zero_are_valid() {
+        assert!(validate(&QExpr::Boost(Boost::new(term("a"), 0.0))).is_ok());
+        assert!(validate(&QExpr::Boost(Boost::new(term("a"), -0.0))).is_ok());
+    }
+
+    #[test]
+    fn negative_weight_is_rejected() {
+        assert_eq!(
+            validate(&QExpr::Boost(Boost::new(term("a"), -1.0))),
+            Err(ValidateError::InvalidBoost)
+        );
+    }
+
+    #[test]
+    fn non_finite_weights_are_rejected() {
+        assert_eq!(
+            validate(&QExpr::Boost(Boost::new(term("a"), f32::NAN))),
+            Err(ValidateError::InvalidBoost)
+        );
+        assert_eq!(
+            validate(&QExpr::Boost(Boost::new(term("a"), f32::INFINITY))),
+            Err(ValidateError::InvalidBoost)
+        );
+    }
+
+    #[test]
+    fn equality_and_hash_compare_weight_by_bit_pattern() {
+        use std::collections::HashSet;
+
+        let a = Boost::new(term("x"), 1.0);
+        let b = Boost::new(term("x"), 1.0);
+        assert_eq!(a, b);
+
+        let nan1 = Boost::new(term("x"), f32::NAN);
+        let nan2 = Boost::new(term("x"), f32::NAN);
+        assert_eq!(nan1, nan2, "NaN compares equal to itself by bit pattern");
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::*;
+
+    #[test]
+    fn equal_bounds_are_valid() {
+        let r = Range::new(
+            FieldName::new("count"),
+            Some(RangeBound::Integer(5)),
+            true,
+            Some(RangeBound::Integer(5)),
+            true,
+        );
+        assert!(validate(&QExpr::Range(r)).is_ok());
+    }
+
+    #[test]
+    fn inverted_integer_bounds_are_rejected() {
+        let r = Range::new(
+            FieldName::new("count"),
+            Some(RangeBound::Integer(10)),
+            true,
+            Some(RangeBound::Integer(1)),
+            true,
+        );
+        assert_eq!(
+            validate(&QExpr::Range(r)),
+            Err(ValidateError::InvertedRange)
+        );
+    }
+
+    #[test]
+    fn inverted_float_bounds_are_rejected() {
+        let r = Range::new(
+            FieldName::new("price"),
+            Some(RangeBound::Float(10.0)),
+            true,
+            Some(RangeBound::Float(1.0)),
+            true,
+        );
+        assert_eq!(
+            validate(&QExpr::Range(r)),
+            Err(ValidateError::InvertedRange)
+        );
+    }
+
+    #[test]
+    fn mismatched_bound_types_are_not_treated_as_inverted() {
+        let r = Range::new(
+            FieldName::new("date"),
+            Some(RangeBound::Text("2020".to_string())),
+            true,
+            Some(RangeBound::Integer(2021)),
+            true,
+        );
+        assert!(validate(&QExpr::Range(r)).is_ok());
+    }
+
+    #[test]
+    fn both_bounds_absent_is_rejected() {
+        let r = Range::new(FieldName::new("count"), None, true, None, true);
+        assert_eq!(validate(&QExpr::Range(r)), Err(ValidateError::EmptyRange));
+    }
+
+    #[test]
+    fn blank_field_name_is_rejected() {
+        let r = Range::new(
+            FieldName::new("   "),
+            Some(RangeBound::Integer(1)),
+            true,
+            Some(RangeBound::Integer(5)),
+            true,
+        );
+        assert_eq!(
+            validate(&QExpr::Range(r)),
+            Err(ValidateError::BlankFieldName)
+        );
+    }
+}
+
+#[cfg(test)]
+mod wildcard_tests {
+    use super::*;
+
+    #[test]
+    fn blank_pattern_is_rejected() {
+        let w = Wildcard::new(None, "   ", None, WildcardKind::Prefix);
+        assert_eq!(
+            validate(&QExpr::Wildcard(w)),
+            Err(ValidateError::BlankWildcard)
+        );
+    }
+
+    #[test]
+    fn all_wildcard_chars_pattern_is_rejected() {
+        let w = Wildcard::new(None, "***", None, WildcardKind::Glob);
+        assert_eq!(
+            validate(&QExpr::Wildcard(w)),
+            Err(ValidateError::BlankWildcard)
+        );
+        let w = Wildcard::new(None, "?", None, WildcardKind::Glob);
+        assert_eq!(
+            validate(&QExpr::Wildcard(w)),
+            Err(ValidateError::BlankWildcard)
+        );
+    }
+
+    #[test]
+    fn mixed_pattern_is_accepted() {
+        let w = Wildcard::new(None, "quer*", None, WildcardKind::Prefix);
+        assert!(validate(&QExpr::Wildcard(w)).is_ok());
+        let w = Wildcard::new(None, "?ase", None, WildcardKind::Glob);
+        assert!(validate(&QExpr::Wildcard(w)).is_ok());
+    }
+
+    #[test]
+    fn blank_scoping_field_is_rejected() {
+        let w = Wildcard::new(
+            Some(FieldName::new("   ")),
+            "quer*",
+            None,
+            WildcardKind::Prefix,
+        );
+        assert_eq!(
+            validate(&QExpr::Wildcard(w)),
+            Err(ValidateError::BlankFieldName)
+        );
+    }
+}
+
+#[cfg(test)]
+mod synonym_tests {
+    use super::*;
+
+    fn term(s: &str) -> QExpr {
+        QExpr::Term(Term::new(s))
+    }
+
+    #[test]
+    fn empty_synonym_is_rejected() {
+        assert_eq!(
+            validate(&QExpr::Synonym(vec![])),
+            Err(ValidateError::EmptyJunction)
+        );
+    }
+
+    #[test]
+    fn non_empty_synonym_recurses_into_children() {
+        assert!(validate(&QExpr::Synonym(vec![term("a"), term("b")])).is_ok());
+        assert_eq!(
+            validate(&QExpr::Synonym(vec![term("a"), term("")])),
+            Err(ValidateError::BlankTerm)
+        );
+    }
+}
+
+#[cfg(test)]
+mod phrase_tests {
+    use super::*;
+
+    #[test]
+    fn only_gaps_is_blank() {
+        let p = Phrase::new(vec![PhraseSlot::Gap, PhraseSlot::Gap]);
+        assert!(p.is_blank());
+        assert_eq!(
+            validate(&QExpr::Phrase(p)),
+            Err(ValidateError::BlankPhrase)
+        );
+    }
+
+    #[test]
+    fn terms_interspersed_with_gaps_is_not_blank() {
+        let p = Phrase::new(vec![
+            PhraseSlot::Term(Term::new("king")),
+            PhraseSlot::Gap,
+            PhraseSlot::Term(Term::new("england")),
+        ]);
+        assert!(!p.is_blank());
+        assert!(validate(&QExpr::Phrase(p)).is_ok());
+    }
+
+    #[test]
+    fn from_terms_has_no_gaps() {
+        let p = Phrase::from_terms(vec![Term::new("king"), Term::new("england")]);
+        assert_eq!(
+            p.terms,
+            vec![
+                PhraseSlot::Term(Term::new("king")),
+                PhraseSlot::Term(Term::new("england")),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod visitor_tests {
+    use super::*;
+
+    fn term(s: &str) -> QExpr {
+        QExpr::Term(Term::new(s))
+    }
+
+    #[test]
+    fn collect_terms_visits_every_term() {
+        let expr = QExpr::And(vec![
+            QExpr::Field(FieldName::new("title"), Box::new(term("a"))),
+            QExpr::Not(Box::new(term("b"))),
+            QExpr::Boost(Boost::new(term("c"), 2.0)),
+        ]);
+        let terms: Vec<&str> = collect_terms(&expr).into_iter().map(|t| t.0.as_str()).collect();
+        assert_eq!(terms, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn collect_terms_reaches_into_phrase_and_near() {
+        let expr = QExpr::And(vec![
+            QExpr::Phrase(Phrase::new(vec![
+                PhraseSlot::Term(Term::new("king")),
+                PhraseSlot::Gap,
+                PhraseSlot::Term(Term::new("england")),
+            ])),
+            QExpr::Near(Near::new(
+                vec![Term::new("fast"), Term::new("car")],
+                5,
+                false,
+            )),
+        ]);
+        let terms: Vec<&str> = collect_terms(&expr).into_iter().map(|t| t.0.as_str()).collect();
+        assert_eq!(terms, vec!["king", "england", "fast", "car"]);
+    }
+
+    #[test]
+    fn map_fields_renames_every_field() {
+        let expr = QExpr::And(vec![
+            QExpr::Field(FieldName::new("title"), Box::new(term("a"))),
+            QExpr::Field(FieldName::new("body"), Box::new(term("b"))),
+        ]);
+        let renamed = map_fields(expr, |name| {
+            if name == FieldName::new("title") {
+                FieldName::new("subject")
+            } else {
+                name
+            }
+        });
+        assert_eq!(
+            renamed,
+            QExpr::And(vec![
+                QExpr::Field(FieldName::new("subject"), Box::new(term("a"))),
+                QExpr::Field(FieldName::new("body"), Box::new(term("b"))),
+            ])
+        );
+    }
+
+    #[test]
+    fn map_fields_also_rewrites_range_and_wildcard_fields() {
+        let expr = QExpr::And(vec![
+            QExpr::Range(Range::new(
+                FieldName::new("title"),
+                Some(RangeBound::Integer(1)),
+                true,
+                None,
+                true,
+            )),
+            QExpr::Wildcard(Wildcard::new(
+                Some(FieldName::new("title")),
+                "quer*",
+                None,
+                WildcardKind::Prefix,
+            )),
+            QExpr::Wildcard(Wildcard::new(None, "quer*", None, WildcardKind::Prefix)),
+        ]);
+        let renamed = map_fields(expr, |name| {
+            if name == FieldName::new("title") {
+                FieldName::new("subject")
+            } else {
+                name
+            }
+        });
+        assert_eq!(
+            renamed,
+            QExpr::And(vec![
+                QExpr::Range(Range::new(
+                    FieldName::new("subject"),
+                    Some(RangeBound::Integer(1)),
+                    true,
+                    None,
+                    true,
+                )),
+                QExpr::Wildcard(Wildcard::new(
+                    Some(FieldName::new("subject")),
+                    "quer*",
+                    None,
+                    WildcardKind::Prefix,
+                )),
+                QExpr::Wildcard(Wildcard::new(None, "quer*", None, WildcardKind::Prefix)),
+            ])
+        );
+    }
+
+    #[test]
+    fn custom_visitor_collects_field_names_including_nested_ones() {
+        struct FieldCounter {
+            seen: Vec<String>,
+        }
+
+        impl<'a> Visitor<'a> for FieldCounter {
+            fn visit_field(&mut self, name: &'a FieldName, inner: &'a QExpr) {
+                self.seen.push(name.0.clone());
+                walk(inner, self);
+            }
+        }
+
+        let expr = QExpr::And(vec![
+            QExpr::Field(FieldName::new("title"), Box::new(term("a"))),
+            QExpr::Not(Box::new(QExpr::Field(
+                FieldName::new("body"),
+                Box::new(term("b")),
+            ))),
+        ]);
+        let mut counter = FieldCounter { seen: Vec::new() };
+        walk(&expr, &mut counter);
+        assert_eq!(counter.seen, vec!["title".to_string(), "body".to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod field_name_tests {
+    use super::*;
+
+    #[test]
+    fn normalized_strips_case_and_separators() {
+        assert_eq!(FieldName::new("publishDate").normalized(), "publishdate");
+        assert_eq!(FieldName::new("publish_date").normalized(), "publishdate");
+        assert_eq!(FieldName::new("Publish-Date").normalized(), "publishdate");
+        assert_eq!(FieldName::new("publish date").normalized(), "publishdate");
+    }
+
+    #[test]
+    fn loose_eq_matches_differing_conventions() {
+        assert!(FieldName::new("publishDate").loose_eq(&FieldName::new("publish_date")));
+        assert!(!FieldName::new("publishDate").loose_eq(&FieldName::new("publisher")));
+    }
+
+    #[test]
+    fn canonicalize_field_names_rewrites_every_field_node() {
+        let expr = QExpr::And(vec![
+            QExpr::Field(
+                FieldName::new("publishDate"),
+                Box::new(QExpr::Term(Term::new("a"))),
+            ),
+            QExpr::Field(
+                FieldName::new("Publish-Date"),
+                Box::new(QExpr::Term(Term::new("b"))),
+            ),
+        ]);
+        let canon = canonicalize_field_names(expr);
+        assert_eq!(
+            canon,
+            QExpr::And(vec![
+                QExpr::Field(
+                    FieldName::new("publishdate"),
+                    Box::new(QExpr::Term(Term::new("a")))
+                ),
+                QExpr::Field(
+                    FieldName::new("publishdate"),
+                    Box::new(QExpr::Term(Term::new("b")))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_loose_fields_rejects_what_validate_would_accept() {
+        let expr = QExpr::Field(
+            FieldName::new("Publish-Date"),
+            Box::new(QExpr::Term(Term::new("a"))),
+        );
+        assert!(validate(&expr).is_ok());
+
+        let blank_after_separators_stripped = QExpr::Field(
+            FieldName::new("- _ "),
+            Box::new(QExpr::Term(Term::new("a"))),
+        );
+        assert!(validate(&blank_after_separators_stripped).is_ok());
+        assert_eq!(
+            validate_loose_fields(&blank_after_separators_stripped),
+            Err(ValidateError::BlankFieldName)
+        );
+    }
+
+    #[test]
+    fn normalize_loose_fields_hoists_a_field_across_naming_conventions() {
+        let expr = QExpr::And(vec![
+            QExpr::Field(
+                FieldName::new("publishDate"),
+                Box::new(QExpr::Term(Term::new("a"))),
+            ),
+            QExpr::Field(
+                FieldName::new("publish_date"),
+                Box::new(QExpr::Term(Term::new("b"))),
+            ),
+        ]);
+        // `normalize` alone sees two distinct field names and can't hoist.
+        assert!(matches!(normalize(expr.clone()), QExpr::And(_)));
+        assert_eq!(
+            normalize_loose_fields(expr),
+            QExpr::Field(
+                FieldName::new("publishdate"),
+                Box::new(QExpr::And(vec![
+                    QExpr::Term(Term::new("a")),
+                    QExpr::Term(Term::new("b")),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn canonicalize_field_names_also_unifies_range_and_wildcard_fields() {
+        let expr = QExpr::Range(Range::new(
+            FieldName::new("Publish-Date"),
+            Some(RangeBound::Integer(1)),
+            true,
+            None,
+            true,
+        ));
+        let canon = canonicalize_field_names(expr);
+        assert_eq!(
+            canon,
+            QExpr::Range(Range::new(
+                FieldName::new("publishdate"),
+                Some(RangeBound::Integer(1)),
+                true,
+                None,
+                true,
+            ))
+        );
+
+        let expr = QExpr::Wildcard(Wildcard::new(
+            Some(FieldName::new("Publish-Date")),
+            "quer*",
+            None,
+            WildcardKind::Prefix,
+        ));
+        let canon = canonicalize_field_names(expr);
+        assert_eq!(
+            canon,
+            QExpr::Wildcard(Wildcard::new(
+                Some(FieldName::new("publishdate")),
+                "quer*",
+                None,
+                WildcardKind::Prefix,
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod normalize_tests {
+    use super::*;
+
+    fn term(s: &str) -> QExpr {
+        QExpr::Term(Term::new(s))
+    }
+
+    #[test]
+    fn flattens_nested_same_kind_junctions() {
+        let expr = QExpr::And(vec![
+            QExpr::And(vec![term("a"), term("b")]),
+            term("c"),
+        ]);
+        assert_eq!(
+            normalize(expr),
+            QExpr::And(vec![term("a"), term("b"), term("c")])
+        );
+    }
+
+    #[test]
+    fn collapses_single_child_junctions() {
+        let expr = QExpr::And(vec![term("a")]);
+        assert_eq!(normalize(expr), term("a"));
+    }
+
+    #[test]
+    fn dedups_preserving_first_seen_order() {
+        let expr = QExpr::Or(vec![term("b"), term("a"), term("b")]);
+        assert_eq!(normalize(expr), QExpr::Or(vec![term("b"), term("a")]));
+    }
+
+    #[test]
+    fn pushes_negation_with_de_morgan() {
+        let expr = QExpr::Not(Box::new(QExpr::And(vec![term("a"), term("b")])));
+        assert_eq!(
+            normalize(expr),
+            QExpr::Or(vec![
+                QExpr::Not(Box::new(term("a"))),
+                QExpr::Not(Box::new(term("b"))),
+            ])
+        );
+    }
+
+    #[test]
+    fn folds_double_negation() {
+        let expr = QExpr::Not(Box::new(QExpr::Not(Box::new(term("a")))));
+        assert_eq!(normalize(expr), term("a"));
+    }
+
+    #[test]
+    fn hoists_common_field_wrapper() {
+        let expr = QExpr::And(vec![
+            QExpr::Field(FieldName::new("title"), Box::new(term("a"))),
+            QExpr::Field(FieldName::new("title"), Box::new(term("b"))),
+        ]);
+        assert_eq!(
+            normalize(expr),
+            QExpr::Field(
+                FieldName::new("title"),
+                Box::new(QExpr::And(vec![term("a"), term("b")]))
+            )
+        );
+    }
+
+    #[test]
+    fn is_idempotent_on_representative_trees() {
+        let trees = vec![
+            QExpr::And(vec![
+                QExpr::And(vec![term("a"), term("b")]),
+                term("a"),
+                QExpr::Not(Box::new(QExpr::Not(Box::new(term("c"))))),
+            ]),
+            QExpr::Not(Box::new(QExpr::Or(vec![
+                QExpr::Field(FieldName::new("f"), Box::new(term("x"))),
+                QExpr::Field(FieldName::new("f"), Box::new(term("y"))),
+            ]))),
+            QExpr::Synonym(vec![term("a"), term("b")]),
+        ];
+        for tree in trees {
+            let once = normalize(tree.clone());
+            let twice = normalize(once.clone());
+            assert_eq!(once, twice, "normalize not idempotent for {tree:?}");
+        }
+    }
+
+    #[test]
+    fn never_invalidates_a_valid_expression() {
+        let expr = QExpr::Not(Box::new(QExpr::And(vec![
+            QExpr::Field(FieldName::new("f"), Box::new(term("x"))),
+            QExpr::Field(FieldName::new("f"), Box::new(term("y"))),
+        ])));
+        assert!(validate(&expr).is_ok());
+        assert!(validate(&normalize(expr)).is_ok());
     }
 }